@@ -2,6 +2,12 @@
 
 #![allow(clippy::comparison_chain)]
 
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
 use crate::*;
 
 // ----------------------------------------------------------------------------
@@ -34,78 +40,1153 @@ pub struct HLine {
     stroke: Stroke,
 }
 
-impl HLine {
-    pub fn new(y: impl Into<f64>, stroke: impl Into<Stroke>) -> Self {
-        Self {
-            y: y.into(),
-            stroke: stroke.into(),
-        }
+impl HLine {
+    pub fn new(y: impl Into<f64>, stroke: impl Into<Stroke>) -> Self {
+        Self {
+            y: y.into(),
+            stroke: stroke.into(),
+        }
+    }
+}
+
+impl PlotItem for HLine {
+    fn shapes(&self, _ui: &Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let rect = transform.screen_rect();
+        let bounds = transform.bounds();
+        let screen_y = transform
+            .position_from_value(&Value::new(bounds.min.x as f64, self.y))
+            .y;
+        shapes.push(Shape::line_segment(
+            [pos2(rect.left(), screen_y), pos2(rect.right(), screen_y)],
+            self.stroke,
+        ));
+    }
+
+    /// The x-bounds are left untouched; `y` is folded into `Plot::bounds` by
+    /// [`Plot::hline`] via [`Plot::include_y`] before this item is stored.
+    fn bounds(&self) -> Rect {
+        Rect::NOTHING
+    }
+
+    fn find_closest(&self, _transform: &ScreenTransform, _pointer: Pos2) -> Option<HoverHit> {
+        None
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A vertical line in a plot, filling the full height
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VLine {
+    x: f64,
+    stroke: Stroke,
+}
+
+impl VLine {
+    pub fn new(x: impl Into<f64>, stroke: impl Into<Stroke>) -> Self {
+        Self {
+            x: x.into(),
+            stroke: stroke.into(),
+        }
+    }
+}
+
+impl PlotItem for VLine {
+    fn shapes(&self, _ui: &Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let rect = transform.screen_rect();
+        let bounds = transform.bounds();
+        let screen_x = transform
+            .position_from_value(&Value::new(self.x, bounds.min.y as f64))
+            .x;
+        shapes.push(Shape::line_segment(
+            [pos2(screen_x, rect.top()), pos2(screen_x, rect.bottom())],
+            self.stroke,
+        ));
+    }
+
+    /// The y-bounds are left untouched; `x` is folded into `Plot::bounds` by
+    /// [`Plot::vline`] via [`Plot::include_x`] before this item is stored.
+    fn bounds(&self) -> Rect {
+        Rect::NOTHING
+    }
+
+    fn find_closest(&self, _transform: &ScreenTransform, _pointer: Pos2) -> Option<HoverHit> {
+        None
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How to resolve overlapping/self-intersecting regions of a filled path, e.g. a
+/// closed parametric curve that crosses itself. Mirrors the even-odd/non-zero fill
+/// rules used by shape renderers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillRule {
+    /// A point is inside if the signed winding number around it is non-zero.
+    NonZero,
+    /// A point is inside if a ray from it crosses the path an odd number of times.
+    EvenOdd,
+}
+
+impl Default for FillRule {
+    fn default() -> Self {
+        Self::NonZero
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A function `f(x) -> y`, sampled adaptively by [`Curve::from_explicit_fn`].
+type ExplicitGenerator = dyn Fn(f64) -> f64 + Send + Sync;
+
+/// A series of values forming a path.
+#[derive(Clone)]
+pub struct Curve {
+    values: Vec<Value>,
+    /// If set, `values` is empty and the curve is instead sampled at draw time,
+    /// adaptively, over this range.
+    generator: Option<(Arc<ExplicitGenerator>, f64, f64)>,
+    bounds: Rect, // TODO: f64
+    stroke: Stroke,
+    name: String,
+    /// If set, shade the region between the curve and `y = 0` with this color.
+    fill: Option<Color32>,
+    fill_rule: FillRule,
+    /// Memoized result of the last [`Self::resolved_values`] call for a
+    /// [`Self::from_explicit_fn`] curve, keyed by the transform it was sampled under.
+    /// Both [`PlotItem::shapes`] and [`PlotItem::find_closest`] resolve values every
+    /// frame, so without this the (potentially expensive) adaptive sampling pass would
+    /// run twice per frame whenever the plot is hovered.
+    sample_cache: RefCell<Option<(SampleCacheKey, Vec<Value>)>>,
+}
+
+impl PartialEq for Curve {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+            && match (&self.generator, &other.generator) {
+                (Some((a, ax0, ax1)), Some((b, bx0, bx1))) => {
+                    Arc::ptr_eq(a, b) && ax0 == bx0 && ax1 == bx1
+                }
+                (None, None) => true,
+                _ => false,
+            }
+            && self.bounds == other.bounds
+            && self.stroke == other.stroke
+            && self.name == other.name
+            && self.fill == other.fill
+            && self.fill_rule == other.fill_rule
+    }
+}
+
+/// Identifies the transform state a [`Curve::sample_cache`] entry was sampled under.
+type SampleCacheKey = (Rect, Rect, bool, bool);
+
+impl Curve {
+    pub fn from_values(values: Vec<Value>) -> Self {
+        let mut bounds = Rect::NOTHING;
+        for value in &values {
+            bounds.extend_with(pos2(value.x as f32, value.y as f32));
+        }
+        Self {
+            values,
+            generator: None,
+            bounds,
+            stroke: Stroke::new(1.5, Color32::from_gray(120)),
+            name: Default::default(),
+            fill: None,
+            fill_rule: FillRule::default(),
+            sample_cache: RefCell::new(None),
+        }
+    }
+
+    pub fn from_iter(iter: impl Iterator<Item = Value>) -> Self {
+        Self::from_values(iter.collect())
+    }
+
+    /// From a series of y-values.
+    /// The x-values will be the indices of these values
+    pub fn from_ys_f32(ys: &[f32]) -> Self {
+        let values: Vec<Value> = ys
+            .iter()
+            .enumerate()
+            .map(|(i, &y)| Value {
+                x: i as f64,
+                y: y as f64,
+            })
+            .collect();
+        Self::from_values(values)
+    }
+
+    /// Plot `function` over `x_range`, sampling it adaptively at draw time instead of
+    /// pre-baking a fixed number of points: flat stretches get few samples, sharp
+    /// features get many, and zooming in reveals more detail. See
+    /// [`sample_curve`] for the subdivision algorithm.
+    pub fn from_explicit_fn(
+        function: impl Fn(f64) -> f64 + Send + Sync + 'static,
+        x_range: RangeInclusive<f64>,
+    ) -> Self {
+        let (min_x, max_x) = (*x_range.start(), *x_range.end());
+
+        // A handful of probe samples so the plot has something to auto-fit bounds to
+        // before the first real (resolution-aware) sampling pass.
+        let mut bounds = Rect::NOTHING;
+        let probe_count = 64;
+        for i in 0..=probe_count {
+            let x = lerp(min_x..=max_x, i as f64 / probe_count as f64);
+            bounds.extend_with(pos2(x as f32, function(x) as f32));
+        }
+
+        Self {
+            values: Vec::new(),
+            generator: Some((Arc::new(function), min_x, max_x)),
+            bounds,
+            stroke: Stroke::new(1.5, Color32::from_gray(120)),
+            name: Default::default(),
+            fill: None,
+            fill_rule: FillRule::default(),
+            sample_cache: RefCell::new(None),
+        }
+    }
+
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width (in points).
+    pub fn width(mut self, width: f32) -> Self {
+        self.stroke.width = width;
+        self
+    }
+
+    /// Stroke color.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Name of this curve.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Shade the region between the curve and `y = 0` with `color`.
+    pub fn fill(mut self, color: impl Into<Color32>) -> Self {
+        self.fill = Some(color.into());
+        self
+    }
+
+    /// Fill rule to use when this curve (or [`Plot::area_between`] using it) shades a
+    /// self-intersecting or concave region. Default: [`FillRule::NonZero`].
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// The points to draw/hover-test: either the stored values, or, for a
+    /// [`Curve::from_explicit_fn`] curve, a fresh adaptive sampling pass at the
+    /// current screen resolution.
+    fn resolved_values(&self, transform: &ScreenTransform) -> Cow<'_, [Value]> {
+        match &self.generator {
+            Some((function, x0, x1)) => {
+                let key: SampleCacheKey = (
+                    transform.bounds(),
+                    transform.screen_rect(),
+                    transform.log_x,
+                    transform.log_y,
+                );
+                if let Some((cached_key, cached_values)) = &*self.sample_cache.borrow() {
+                    if *cached_key == key {
+                        return Cow::Owned(cached_values.clone());
+                    }
+                }
+                let values = sample_curve(transform, function.as_ref(), *x0, *x1);
+                *self.sample_cache.borrow_mut() = Some((key, values.clone()));
+                Cow::Owned(values)
+            }
+            None => Cow::Borrowed(&self.values),
+        }
+    }
+}
+
+impl PlotItem for Curve {
+    fn shapes(&self, _ui: &Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let values = self.resolved_values(transform);
+
+        if let Some(fill_color) = self.fill {
+            if values.len() > 1 {
+                let mut points: Vec<Pos2> = values
+                    .iter()
+                    .map(|v| transform.position_from_value(v))
+                    .collect();
+                points.extend(
+                    values
+                        .iter()
+                        .rev()
+                        .map(|v| transform.position_from_value(&Value::new(v.x, 0.0))),
+                );
+                fill_polygon(
+                    &points,
+                    self.fill_rule,
+                    fill_color,
+                    transform.screen_rect(),
+                    shapes,
+                );
+            }
+        }
+
+        if values.len() == 1 {
+            let point = transform.position_from_value(&values[0]);
+            shapes.push(Shape::circle_filled(
+                point,
+                self.stroke.width / 2.0,
+                self.stroke.color,
+            ));
+        } else if values.len() > 1 {
+            shapes.push(Shape::line(
+                values
+                    .iter()
+                    .map(|v| transform.position_from_value(v))
+                    .collect(),
+                self.stroke,
+            ));
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn find_closest(&self, transform: &ScreenTransform, pointer: Pos2) -> Option<HoverHit> {
+        let prefix = if self.name.is_empty() {
+            String::new()
+        } else {
+            format!("{}\n", self.name)
+        };
+
+        let mut best: Option<HoverHit> = None;
+        for value in self.resolved_values(transform).iter() {
+            let dist_sq = pointer.distance_sq(transform.position_from_value(value));
+            if best.as_ref().map_or(true, |hit| dist_sq < hit.dist_sq) {
+                best = Some(HoverHit {
+                    value: *value,
+                    dist_sq,
+                    prefix: prefix.clone(),
+                    detail: None,
+                });
+            }
+        }
+        best
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A single bar in a [`BarChart`], drawn as a filled rect from `baseline` to `height`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bar {
+    pub x: f64,
+    pub height: f64,
+    pub width: f64,
+}
+
+impl Bar {
+    pub fn new(x: impl Into<f64>, height: impl Into<f64>, width: impl Into<f64>) -> Self {
+        Self {
+            x: x.into(),
+            height: height.into(),
+            width: width.into(),
+        }
+    }
+}
+
+/// A set of bars, e.g. a histogram.
+#[derive(Clone, PartialEq)]
+pub struct BarChart {
+    bars: Vec<Bar>,
+    baseline: f64,
+    fill: Color32,
+    stroke: Stroke,
+    name: String,
+}
+
+impl BarChart {
+    pub fn new(bars: Vec<Bar>) -> Self {
+        Self {
+            bars,
+            baseline: 0.0,
+            fill: Color32::from_gray(100),
+            stroke: Stroke::new(1.0, Color32::from_gray(200)),
+            name: Default::default(),
+        }
+    }
+
+    /// The y-value bars are drawn from. Default: `0.0`.
+    pub fn baseline(mut self, baseline: impl Into<f64>) -> Self {
+        self.baseline = baseline.into();
+        self
+    }
+
+    /// Fill color of the bars.
+    pub fn color(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = fill.into();
+        self
+    }
+
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Name of this bar chart.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+}
+
+impl PlotItem for BarChart {
+    fn shapes(&self, _ui: &Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        for bar in &self.bars {
+            let min =
+                transform.position_from_value(&Value::new(bar.x - bar.width / 2.0, self.baseline));
+            let max =
+                transform.position_from_value(&Value::new(bar.x + bar.width / 2.0, bar.height));
+            shapes.push(Shape::Rect {
+                rect: Rect::from_two_pos(min, max),
+                corner_radius: 0.0,
+                fill: self.fill,
+                stroke: self.stroke,
+            });
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        let mut bounds = Rect::NOTHING;
+        for bar in &self.bars {
+            bounds.extend_with(pos2((bar.x - bar.width / 2.0) as f32, self.baseline as f32));
+            bounds.extend_with(pos2((bar.x + bar.width / 2.0) as f32, bar.height as f32));
+        }
+        bounds
+    }
+
+    fn find_closest(&self, transform: &ScreenTransform, pointer: Pos2) -> Option<HoverHit> {
+        let prefix = if self.name.is_empty() {
+            String::new()
+        } else {
+            format!("{}\n", self.name)
+        };
+
+        let d_dpixel = transform.bounds().size() / transform.screen_rect().size();
+        let x_decimals = decimals_for_resolution(d_dpixel.x);
+        let y_decimals = decimals_for_resolution(d_dpixel.y);
+
+        let mut best: Option<HoverHit> = None;
+        for bar in &self.bars {
+            let value = Value::new(bar.x, bar.height);
+            let dist_sq = pointer.distance_sq(transform.position_from_value(&value));
+            if best.as_ref().map_or(true, |hit| dist_sq < hit.dist_sq) {
+                best = Some(HoverHit {
+                    value,
+                    dist_sq,
+                    prefix: prefix.clone(),
+                    detail: Some(format!(
+                        "x = {:.*}\nheight = {:.*}",
+                        x_decimals, bar.x, y_decimals, bar.height
+                    )),
+                });
+            }
+        }
+        best
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn color(&self) -> Color32 {
+        self.fill
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A single box-and-whisker element in a [`BoxPlot`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoxElem {
+    pub position: f64,
+    pub lower_whisker: f64,
+    pub quartile1: f64,
+    pub median: f64,
+    pub quartile3: f64,
+    pub upper_whisker: f64,
+}
+
+impl BoxElem {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        position: impl Into<f64>,
+        lower_whisker: impl Into<f64>,
+        quartile1: impl Into<f64>,
+        median: impl Into<f64>,
+        quartile3: impl Into<f64>,
+        upper_whisker: impl Into<f64>,
+    ) -> Self {
+        Self {
+            position: position.into(),
+            lower_whisker: lower_whisker.into(),
+            quartile1: quartile1.into(),
+            median: median.into(),
+            quartile3: quartile3.into(),
+            upper_whisker: upper_whisker.into(),
+        }
+    }
+}
+
+/// A set of per-category box-and-whisker plots.
+#[derive(Clone, PartialEq)]
+pub struct BoxPlot {
+    boxes: Vec<BoxElem>,
+    /// `(position, value)` points drawn as dots beyond the whiskers.
+    outliers: Vec<Value>,
+    width: f64,
+    stroke: Stroke,
+    fill: Color32,
+    name: String,
+}
+
+impl BoxPlot {
+    pub fn new(boxes: Vec<BoxElem>) -> Self {
+        Self {
+            boxes,
+            outliers: Vec::new(),
+            width: 0.7,
+            stroke: Stroke::new(1.0, Color32::from_gray(200)),
+            fill: Color32::from_gray(60),
+            name: Default::default(),
+        }
+    }
+
+    /// Width of each box, centered on its category position. The whisker caps are
+    /// half as wide.
+    pub fn width(mut self, width: impl Into<f64>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Outlier points, drawn as dots.
+    pub fn outliers(mut self, outliers: Vec<Value>) -> Self {
+        self.outliers = outliers;
+        self
+    }
+
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Fill color of the boxes.
+    pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = fill.into();
+        self
+    }
+
+    /// Name of this box plot.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+}
+
+impl PlotItem for BoxPlot {
+    fn shapes(&self, _ui: &Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let half_width = self.width / 2.0;
+        for b in &self.boxes {
+            let to_screen = |x: f64, y: f64| transform.position_from_value(&Value::new(x, y));
+
+            // Whisker, from lower to upper extreme, drawn behind the box.
+            shapes.push(Shape::line_segment(
+                [
+                    to_screen(b.position, b.lower_whisker),
+                    to_screen(b.position, b.upper_whisker),
+                ],
+                self.stroke,
+            ));
+            // Whisker caps.
+            for &whisker in &[b.lower_whisker, b.upper_whisker] {
+                shapes.push(Shape::line_segment(
+                    [
+                        to_screen(b.position - half_width * 0.5, whisker),
+                        to_screen(b.position + half_width * 0.5, whisker),
+                    ],
+                    self.stroke,
+                ));
+            }
+
+            // Box, from quartile1 to quartile3.
+            let box_min = to_screen(b.position - half_width, b.quartile1);
+            let box_max = to_screen(b.position + half_width, b.quartile3);
+            shapes.push(Shape::Rect {
+                rect: Rect::from_two_pos(box_min, box_max),
+                corner_radius: 0.0,
+                fill: self.fill,
+                stroke: self.stroke,
+            });
+
+            // Median line.
+            shapes.push(Shape::line_segment(
+                [
+                    to_screen(b.position - half_width, b.median),
+                    to_screen(b.position + half_width, b.median),
+                ],
+                self.stroke,
+            ));
+        }
+
+        for outlier in &self.outliers {
+            shapes.push(Shape::circle_filled(
+                transform.position_from_value(outlier),
+                2.0,
+                self.stroke.color,
+            ));
+        }
+    }
+
+    fn bounds(&self) -> Rect {
+        let mut bounds = Rect::NOTHING;
+        let half_width = self.width / 2.0;
+        for b in &self.boxes {
+            bounds.extend_with(pos2(
+                (b.position - half_width) as f32,
+                b.lower_whisker as f32,
+            ));
+            bounds.extend_with(pos2(
+                (b.position + half_width) as f32,
+                b.upper_whisker as f32,
+            ));
+        }
+        for outlier in &self.outliers {
+            bounds.extend_with(pos2(outlier.x as f32, outlier.y as f32));
+        }
+        bounds
+    }
+
+    fn find_closest(&self, transform: &ScreenTransform, pointer: Pos2) -> Option<HoverHit> {
+        let prefix = if self.name.is_empty() {
+            String::new()
+        } else {
+            format!("{}\n", self.name)
+        };
+
+        let d_dpixel = transform.bounds().size() / transform.screen_rect().size();
+        let decimals = decimals_for_resolution(d_dpixel.y.max(d_dpixel.x));
+
+        let mut best: Option<HoverHit> = None;
+        for b in &self.boxes {
+            let value = Value::new(b.position, b.median);
+            let dist_sq = pointer.distance_sq(transform.position_from_value(&value));
+            if best.as_ref().map_or(true, |hit| dist_sq < hit.dist_sq) {
+                best = Some(HoverHit {
+                    value,
+                    dist_sq,
+                    prefix: prefix.clone(),
+                    detail: Some(format!(
+                        "median = {:.*}\nq1 = {:.*}, q3 = {:.*}\nwhiskers = {:.*}..{:.*}",
+                        decimals,
+                        b.median,
+                        decimals,
+                        b.quartile1,
+                        decimals,
+                        b.quartile3,
+                        decimals,
+                        b.lower_whisker,
+                        decimals,
+                        b.upper_whisker
+                    )),
+                });
+            }
+        }
+        best
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn color(&self) -> Color32 {
+        self.fill
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The region between two curves, shaded with `a`'s fill color/rule (see
+/// [`Plot::area_between`]).
+#[derive(Clone, PartialEq)]
+struct AreaBetween {
+    a: Curve,
+    b: Curve,
+}
+
+impl PlotItem for AreaBetween {
+    fn shapes(&self, _ui: &Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let a_values = self.a.resolved_values(transform);
+        let b_values = self.b.resolved_values(transform);
+        if a_values.len() < 2 || b_values.len() < 2 {
+            return;
+        }
+        let fill_color = self
+            .a
+            .fill
+            .unwrap_or_else(|| self.a.stroke.color.linear_multiply(0.3));
+
+        let mut points: Vec<Pos2> = a_values
+            .iter()
+            .map(|v| transform.position_from_value(v))
+            .collect();
+        points.extend(
+            b_values
+                .iter()
+                .rev()
+                .map(|v| transform.position_from_value(v)),
+        );
+        fill_polygon(
+            &points,
+            self.a.fill_rule,
+            fill_color,
+            transform.screen_rect(),
+            shapes,
+        );
+    }
+
+    fn bounds(&self) -> Rect {
+        self.a.bounds.union(self.b.bounds)
+    }
+
+    fn find_closest(&self, _transform: &ScreenTransform, _pointer: Pos2) -> Option<HoverHit> {
+        None
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Pick a human-readable step between axis ticks ("nice numbers").
+///
+/// `span` is the value range covered by the axis, `pixel_len` is how much screen space
+/// is available for it, and `min_label_spacing` is the minimum pixel gap we want between
+/// tick labels (used to derive a target tick count).
+fn nice_tick_step(span: f64, pixel_len: f32, min_label_spacing: f32) -> f64 {
+    if !span.is_finite() || span <= 0.0 {
+        return 1.0;
+    }
+    let target_ticks = (pixel_len / min_label_spacing).max(1.0) as f64;
+    let raw_step = span / target_ticks;
+    let magnitude = 10f64.powf(raw_step.log10().floor());
+    let norm = raw_step / magnitude;
+    let nice = if norm <= 1.0 {
+        1.0
+    } else if norm <= 2.0 {
+        2.0
+    } else if norm <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+/// Evenly spaced tick values in `[min, max]`, `step` apart, starting at the first
+/// multiple of `step` that is `>= min`.
+fn generate_ticks(min: f64, max: f64, step: f64) -> Vec<f64> {
+    if !step.is_finite() || step <= 0.0 || !min.is_finite() || !max.is_finite() {
+        return Vec::new();
+    }
+    let first = (min / step).ceil() * step;
+    let epsilon = step * 1e-6;
+    let mut ticks = Vec::new();
+    let mut value = first;
+    while value <= max + epsilon && ticks.len() < 1_000 {
+        ticks.push(value);
+        value += step;
+    }
+    ticks
+}
+
+/// How many decimals to show for values that are `value_per_pixel` apart, so that
+/// adjacent pixels don't round to the same label.
+fn decimals_for_resolution(value_per_pixel: f32) -> usize {
+    ((-value_per_pixel.log10()).ceil().at_least(0.0) as usize).at_most(6)
+}
+
+/// How many decimals to show for a log-scaled tick, so that e.g. `0.1` and `0.01`
+/// don't both round to `"0"`. Linear pixel resolution is meaningless here: a log
+/// axis typically spans many orders of magnitude, so decimals must come from the
+/// tick's own magnitude instead.
+fn decimals_for_log_tick(tick: f64) -> usize {
+    if !tick.is_finite() || tick <= 0.0 {
+        return 0;
+    }
+    ((-tick.log10()).ceil().at_least(0.0) as usize).at_most(6)
+}
+
+/// Ticks for a log10-scaled axis: major ticks at powers of ten (1, 10, 100, …) and
+/// minor ticks at the 2..=9 multiples of each decade, restricted to `[min, max]`.
+/// `max` is first raised to at least `10 * min`, so at least one decade is covered
+/// and ticks may lie above the original `max`.
+fn generate_log_ticks(min: f64, max: f64) -> (Vec<f64>, Vec<f64>) {
+    let min = min.max(f64::MIN_POSITIVE);
+    let max = max.max(min * 10.0);
+    let first_decade = min.log10().floor() as i32;
+    let last_decade = max.log10().ceil() as i32;
+
+    let mut major = Vec::new();
+    let mut minor = Vec::new();
+    for decade in first_decade..=last_decade {
+        let base = 10f64.powi(decade);
+        if base >= min && base <= max {
+            major.push(base);
+        }
+        for multiple in 2..=9 {
+            let value = base * multiple as f64;
+            if value >= min && value <= max {
+                minor.push(value);
+            }
+        }
+    }
+    (major, minor)
+}
+
+/// Adaptively sample `function` over `[x0, x1]`, recursing wherever the curve isn't
+/// well approximated by a straight line at the current screen resolution.
+fn sample_curve(
+    transform: &ScreenTransform,
+    function: &ExplicitGenerator,
+    x0: f64,
+    x1: f64,
+) -> Vec<Value> {
+    const MAX_DEPTH: u32 = 12;
+    const MAX_SAMPLES: usize = 20_000;
+    const TOLERANCE: f32 = 0.3;
+
+    let to_screen = |x: f64, y: f64| transform.position_from_value(&Value::new(x, y));
+
+    // Start from a few uniform intervals, so that a function whose midpoint happens to
+    // lie on the chord between the endpoints (e.g. `sin` over whole periods) is not
+    // taken for a straight line.
+    const INITIAL_INTERVALS: usize = 32;
+
+    let mut out = vec![Value::new(x0, function(x0))];
+    for i in 1..=INITIAL_INTERVALS {
+        let start = *out.last().unwrap();
+        let x = lerp(x0..=x1, i as f64 / INITIAL_INTERVALS as f64);
+        subdivide(
+            function,
+            &to_screen,
+            (start.x, start.y),
+            (x, function(x)),
+            MAX_DEPTH,
+            TOLERANCE,
+            MAX_SAMPLES,
+            &mut out,
+        );
+    }
+    out
+}
+
+/// Recursively subdivide `(x0, y0)..(x1, y1)`, appending the right endpoint of each
+/// flat-enough segment to `out` (the caller is responsible for seeding `out` with the
+/// left endpoint). A segment is "flat" when its midpoint, mapped to screen space,
+/// lies within `tolerance` pixels of the straight line between its screen-space
+/// endpoints (a De Casteljau-style flatness test).
+#[allow(clippy::too_many_arguments)]
+fn subdivide(
+    function: &ExplicitGenerator,
+    to_screen: &impl Fn(f64, f64) -> Pos2,
+    (x0, y0): (f64, f64),
+    (x1, y1): (f64, f64),
+    depth: u32,
+    tolerance: f32,
+    max_samples: usize,
+    out: &mut Vec<Value>,
+) {
+    if depth == 0 || out.len() >= max_samples {
+        out.push(Value::new(x1, y1));
+        return;
+    }
+
+    let xm = 0.5 * (x0 + x1);
+    let ym = function(xm);
+
+    let p0 = to_screen(x0, y0);
+    let p1 = to_screen(x1, y1);
+    let pm = to_screen(xm, ym);
+
+    if point_segment_distance(pm, p0, p1) <= tolerance {
+        out.push(Value::new(x1, y1));
+    } else {
+        subdivide(
+            function,
+            to_screen,
+            (x0, y0),
+            (xm, ym),
+            depth - 1,
+            tolerance,
+            max_samples,
+            out,
+        );
+        subdivide(
+            function,
+            to_screen,
+            (xm, ym),
+            (x1, y1),
+            depth - 1,
+            tolerance,
+            max_samples,
+            out,
+        );
+    }
+}
+
+/// Perpendicular distance from `point` to the segment `a`-`b`.
+fn point_segment_distance(point: Pos2, a: Pos2, b: Pos2) -> f32 {
+    let segment = b - a;
+    let length_sq = segment.length_sq();
+    if length_sq < 1e-9 {
+        return point.distance(a);
+    }
+    let t = (((point - a).x * segment.x + (point - a).y * segment.y) / length_sq).clamp(0.0, 1.0);
+    let closest = a + segment * t;
+    point.distance(closest)
+}
+
+/// Fill a closed polygon (possibly self-intersecting or concave) by scanline
+/// rasterization, honoring `rule` to decide which spans along each scanline are
+/// "inside". Clipped to `clip_rect`. Each inside span is emitted as a thin filled
+/// rect; shapes pushed after them (e.g. the curve's stroke) draw on top.
+fn fill_polygon(
+    points: &[Pos2],
+    rule: FillRule,
+    color: Color32,
+    clip_rect: Rect,
+    shapes: &mut Vec<Shape>,
+) {
+    if points.len() < 3 || color == Color32::TRANSPARENT {
+        return;
+    }
+
+    let min_y = points
+        .iter()
+        .map(|p| p.y)
+        .fold(f32::INFINITY, f32::min)
+        .max(clip_rect.top());
+    let max_y = points
+        .iter()
+        .map(|p| p.y)
+        .fold(f32::NEG_INFINITY, f32::max)
+        .min(clip_rect.bottom());
+    if min_y >= max_y {
+        return;
+    }
+
+    let row_height = ((max_y - min_y) / 400.0).max(2.0);
+    let no_stroke = Stroke::new(0.0, Color32::TRANSPARENT);
+
+    let mut y = min_y;
+    while y < max_y {
+        let scan_y = y + row_height * 0.5;
+
+        let mut crossings: Vec<(f32, i32)> = Vec::new();
+        for i in 0..points.len() {
+            let a = points[i];
+            let b = points[(i + 1) % points.len()];
+            if (a.y <= scan_y) != (b.y <= scan_y) {
+                let t = (scan_y - a.y) / (b.y - a.y);
+                let x = a.x + t * (b.x - a.x);
+                let direction = if b.y > a.y { 1 } else { -1 };
+                crossings.push((x, direction));
+            }
+        }
+        crossings.sort_by(|l, r| l.0.partial_cmp(&r.0).unwrap());
+
+        let mut winding = 0;
+        for pair in crossings.windows(2) {
+            let (x0, direction) = pair[0];
+            let (x1, _) = pair[1];
+            winding += direction;
+            let inside = match rule {
+                FillRule::NonZero => winding != 0,
+                FillRule::EvenOdd => winding % 2 != 0,
+            };
+            let x0 = x0.max(clip_rect.left());
+            let x1 = x1.min(clip_rect.right());
+            if inside && x1 > x0 {
+                shapes.push(Shape::Rect {
+                    rect: Rect::from_min_max(pos2(x0, y), pos2(x1, (y + row_height).min(max_y))),
+                    corner_radius: 0.0,
+                    fill: color,
+                    stroke: no_stroke,
+                });
+            }
+        }
+
+        y += row_height;
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Shared behavior for anything that can be drawn inside a [`Plot`]: a curve, line,
+/// bar chart, or box plot.
+trait PlotItem {
+    /// Paint this item's shapes into `shapes`, mapping value-space to screen-space
+    /// via `transform`.
+    fn shapes(&self, ui: &Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>);
+
+    /// The value-space bounds this item occupies, used to auto-fit the view. Items
+    /// that only constrain one axis (like [`HLine`]/[`VLine`]) return
+    /// [`Rect::NOTHING`] and instead expand the relevant axis directly via
+    /// [`Plot::include_x`]/[`Plot::include_y`] when added.
+    fn bounds(&self) -> Rect;
+
+    /// The value of this item nearest to the pointer, at any distance (`None` if the
+    /// item has no hoverable values). The caller decides whether it is close enough.
+    fn find_closest(&self, transform: &ScreenTransform, pointer: Pos2) -> Option<HoverHit>;
+
+    /// Name shown in a [`Plot::legend`] entry. Empty for anonymous items, which
+    /// are omitted from the legend entirely.
+    fn name(&self) -> &str {
+        ""
+    }
+
+    /// Color swatch shown next to this item's [`Plot::legend`] entry.
+    fn color(&self) -> Color32 {
+        Color32::TRANSPARENT
     }
 }
 
+/// The result of [`PlotItem::find_closest`]: the nearest value under the pointer,
+/// how far away it is (screen-space distance squared), and the tooltip text to show.
+struct HoverHit {
+    value: Value,
+    dist_sq: f32,
+    /// Prepended to the tooltip text, e.g. the curve's name.
+    prefix: String,
+    /// If set, used verbatim as the tooltip body instead of the generic "x = ../y = .."
+    /// text (used by items like [`BarChart`]/[`BoxPlot`] that show more than a point).
+    detail: Option<String>,
+}
+
 // ----------------------------------------------------------------------------
 
-/// A series of values forming a path.
+/// Every kind of item a [`Plot`] can hold. Stored as an enum rather than
+/// `Box<dyn PlotItem>` so that `Plot` itself stays `Clone`/`PartialEq`, matching its
+/// pre-existing public API.
 #[derive(Clone, PartialEq)]
-pub struct Curve {
-    values: Vec<Value>,
-    bounds: Rect, // TODO: f64
-    stroke: Stroke,
-    name: String,
+enum PlotItemKind {
+    HLine(HLine),
+    VLine(VLine),
+    Curve(Curve),
+    BarChart(BarChart),
+    BoxPlot(BoxPlot),
+    AreaBetween(AreaBetween),
 }
 
-impl Curve {
-    pub fn from_values(values: Vec<Value>) -> Self {
-        let mut bounds = Rect::NOTHING;
-        for value in &values {
-            bounds.extend_with(pos2(value.x as f32, value.y as f32));
-        }
-        Self {
-            values,
-            bounds,
-            stroke: Stroke::new(1.5, Color32::from_gray(120)),
-            name: Default::default(),
+impl PlotItem for PlotItemKind {
+    fn shapes(&self, ui: &Ui, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        match self {
+            Self::HLine(item) => item.shapes(ui, transform, shapes),
+            Self::VLine(item) => item.shapes(ui, transform, shapes),
+            Self::Curve(item) => item.shapes(ui, transform, shapes),
+            Self::BarChart(item) => item.shapes(ui, transform, shapes),
+            Self::BoxPlot(item) => item.shapes(ui, transform, shapes),
+            Self::AreaBetween(item) => item.shapes(ui, transform, shapes),
         }
     }
 
-    pub fn from_iter(iter: impl Iterator<Item = Value>) -> Self {
-        Self::from_values(iter.collect())
+    fn bounds(&self) -> Rect {
+        match self {
+            Self::HLine(item) => item.bounds(),
+            Self::VLine(item) => item.bounds(),
+            Self::Curve(item) => item.bounds(),
+            Self::BarChart(item) => item.bounds(),
+            Self::BoxPlot(item) => item.bounds(),
+            Self::AreaBetween(item) => item.bounds(),
+        }
     }
 
-    /// From a series of y-values.
-    /// The x-values will be the indices of these values
-    pub fn from_ys_f32(ys: &[f32]) -> Self {
-        let values: Vec<Value> = ys
-            .iter()
-            .enumerate()
-            .map(|(i, &y)| Value {
-                x: i as f64,
-                y: y as f64,
-            })
-            .collect();
-        Self::from_values(values)
+    fn find_closest(&self, transform: &ScreenTransform, pointer: Pos2) -> Option<HoverHit> {
+        match self {
+            Self::HLine(item) => item.find_closest(transform, pointer),
+            Self::VLine(item) => item.find_closest(transform, pointer),
+            Self::Curve(item) => item.find_closest(transform, pointer),
+            Self::BarChart(item) => item.find_closest(transform, pointer),
+            Self::BoxPlot(item) => item.find_closest(transform, pointer),
+            Self::AreaBetween(item) => item.find_closest(transform, pointer),
+        }
     }
 
-    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
-        self.stroke = stroke.into();
-        self
+    fn name(&self) -> &str {
+        match self {
+            Self::HLine(item) => item.name(),
+            Self::VLine(item) => item.name(),
+            Self::Curve(item) => item.name(),
+            Self::BarChart(item) => item.name(),
+            Self::BoxPlot(item) => item.name(),
+            Self::AreaBetween(item) => item.name(),
+        }
     }
 
-    /// Stroke width (in points).
-    pub fn width(mut self, width: f32) -> Self {
-        self.stroke.width = width;
-        self
+    fn color(&self) -> Color32 {
+        match self {
+            Self::HLine(item) => item.color(),
+            Self::VLine(item) => item.color(),
+            Self::Curve(item) => item.color(),
+            Self::BarChart(item) => item.color(),
+            Self::BoxPlot(item) => item.color(),
+            Self::AreaBetween(item) => item.color(),
+        }
     }
+}
 
-    /// Stroke color.
-    pub fn color(mut self, color: impl Into<Color32>) -> Self {
-        self.stroke.color = color.into();
-        self
+// ----------------------------------------------------------------------------
+
+/// Which corner of the plot to anchor a [`Legend`] to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Corner {
+    LeftTop,
+    RightTop,
+    LeftBottom,
+    RightBottom,
+}
+
+/// Configuration for the legend panel added via [`Plot::legend`], listing every named
+/// item with a color swatch. Clicking an entry toggles that item's visibility.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Legend {
+    corner: Corner,
+}
+
+impl Default for Legend {
+    fn default() -> Self {
+        Self {
+            corner: Corner::RightTop,
+        }
     }
+}
 
-    /// Name of this curve.
-    pub fn name(mut self, name: impl Into<String>) -> Self {
-        self.name = name.into();
+impl Legend {
+    /// Which corner of the plot to anchor the legend to. Default: [`Corner::RightTop`].
+    pub fn position(mut self, corner: Corner) -> Self {
+        self.corner = corner;
         self
     }
 }
@@ -130,8 +1211,7 @@ impl Curve {
 /// ```
 #[derive(Clone, PartialEq)]
 pub struct Plot {
-    curves: Vec<Curve>,
-    hlines: Vec<HLine>,
+    items: Vec<PlotItemKind>,
 
     bounds: Rect, // TODO: f64
     symmetrical_x_bounds: bool,
@@ -144,13 +1224,21 @@ pub struct Plot {
 
     show_x: bool,
     show_y: bool,
+    show_axes: bool,
+    log_x: bool,
+    log_y: bool,
+
+    legend: Option<Legend>,
+    id_source: Option<Id>,
+
+    allow_drag: bool,
+    allow_zoom: bool,
 }
 
 impl Default for Plot {
     fn default() -> Self {
         Self {
-            curves: Default::default(),
-            hlines: Default::default(),
+            items: Default::default(),
 
             bounds: Rect::NOTHING,
             symmetrical_x_bounds: false,
@@ -163,21 +1251,63 @@ impl Default for Plot {
 
             show_x: true,
             show_y: true,
+            show_axes: true,
+            log_x: false,
+            log_y: false,
+
+            legend: None,
+            id_source: None,
+
+            allow_drag: true,
+            allow_zoom: true,
         }
     }
 }
 
 impl Plot {
-    pub fn curve(mut self, curve: Curve) -> Self {
-        self.bounds = self.bounds.union(curve.bounds);
-        self.curves.push(curve);
+    fn push(mut self, item: PlotItemKind) -> Self {
+        self.bounds = self.bounds.union(item.bounds());
+        self.items.push(item);
         self
     }
 
+    pub fn curve(self, curve: Curve) -> Self {
+        self.push(PlotItemKind::Curve(curve))
+    }
+
     /// Add a horizontal line
     pub fn hline(mut self, hline: HLine) -> Self {
         self = self.include_y(hline.y);
-        self.hlines.push(hline);
+        self.push(PlotItemKind::HLine(hline))
+    }
+
+    /// Add a vertical line
+    pub fn vline(mut self, vline: VLine) -> Self {
+        self = self.include_x(vline.x);
+        self.push(PlotItemKind::VLine(vline))
+    }
+
+    /// Add a bar chart, e.g. a histogram.
+    pub fn bar_chart(self, bar_chart: BarChart) -> Self {
+        self.push(PlotItemKind::BarChart(bar_chart))
+    }
+
+    /// Add a box-and-whisker plot.
+    pub fn box_plot(self, box_plot: BoxPlot) -> Self {
+        self.push(PlotItemKind::BoxPlot(box_plot))
+    }
+
+    /// Shade the region between two curves. Call [`Curve::fill`]/[`Curve::fill_rule`]
+    /// on `a` to control the fill color and winding rule.
+    pub fn area_between(self, a: Curve, b: Curve) -> Self {
+        self.push(PlotItemKind::AreaBetween(AreaBetween { a, b }))
+    }
+
+    /// Expand bounds to include the given x value
+    pub fn include_x(mut self, x: impl Into<f64>) -> Self {
+        let x = x.into();
+        self.bounds.min.x = self.bounds.min.x.min(x as f32);
+        self.bounds.max.x = self.bounds.max.x.max(x as f32);
         self
     }
 
@@ -230,13 +1360,58 @@ impl Plot {
         self.show_y = show_y;
         self
     }
+
+    /// Show gridlines and tick labels on the x and y axes. Default: `true`.
+    pub fn show_axes(mut self, show_axes: bool) -> Self {
+        self.show_axes = show_axes;
+        self
+    }
+
+    /// Use a logarithmic (base 10) scale for the x-axis. Non-positive x-values are
+    /// clamped. Useful for data spanning several orders of magnitude.
+    pub fn log_x(mut self) -> Self {
+        self.log_x = true;
+        self
+    }
+
+    /// Use a logarithmic (base 10) scale for the y-axis. Non-positive y-values are
+    /// clamped. Useful for data spanning several orders of magnitude.
+    pub fn log_y(mut self) -> Self {
+        self.log_y = true;
+        self
+    }
+
+    /// Show a legend listing every named item, with a color swatch per entry that can
+    /// be clicked to toggle that item's visibility. Off by default.
+    pub fn legend(mut self, legend: Legend) -> Self {
+        self.legend = Some(legend);
+        self
+    }
+
+    /// Disambiguate multiple plots sharing persisted state (the legend's hidden-items
+    /// set, and the view bounds used for panning/zooming) in the same [`Ui`].
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Whether to allow dragging to pan the view. Default: `true`.
+    pub fn allow_drag(mut self, allow_drag: bool) -> Self {
+        self.allow_drag = allow_drag;
+        self
+    }
+
+    /// Whether to allow scrolling to zoom the view around the pointer. Default: `true`.
+    pub fn allow_zoom(mut self, allow_zoom: bool) -> Self {
+        self.allow_zoom = allow_zoom;
+        self
+    }
 }
 
 impl Widget for Plot {
     fn ui(self, ui: &mut Ui) -> Response {
         let Self {
-            curves,
-            hlines,
+            items,
             bounds,
             symmetrical_x_bounds,
             symmetrical_y_bounds,
@@ -246,8 +1421,17 @@ impl Widget for Plot {
             aspect_ratio,
             show_x,
             show_y,
+            show_axes,
+            log_x,
+            log_y,
+            legend,
+            id_source,
+            allow_drag,
+            allow_zoom,
         } = self;
 
+        let id = ui.make_persistent_id(id_source.unwrap_or_else(|| Id::new("default_plot")));
+
         let width = width.unwrap_or_else(|| {
             if let (Some(h), Some(aspect)) = (height, aspect_ratio) {
                 h * aspect
@@ -262,23 +1446,61 @@ impl Widget for Plot {
                 ui.available_size_before_wrap_finite().y
             }
         });
-        let (rect, response) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
-
-        let mut bounds = bounds;
-
-        if symmetrical_x_bounds {
-            let x_abs = bounds.max.x.abs().max(bounds.min.x.abs());
-            bounds.min.x = -x_abs;
-            bounds.max.x = x_abs;
+        let sense = if allow_drag {
+            Sense::click_and_drag()
+        } else {
+            Sense::click()
         };
-        if symmetrical_y_bounds {
-            let y_abs = bounds.max.y.abs().max(bounds.min.y.abs());
-            bounds.min.y = -y_abs;
-            bounds.max.y = y_abs;
+        let (rect, response) = ui.allocate_exact_size(vec2(width, height), sense);
+
+        let apply_symmetrical = |mut bounds: Rect| {
+            if symmetrical_x_bounds {
+                let x_abs = bounds.max.x.abs().max(bounds.min.x.abs());
+                bounds.min.x = -x_abs;
+                bounds.max.x = x_abs;
+            }
+            if symmetrical_y_bounds {
+                let y_abs = bounds.max.y.abs().max(bounds.min.y.abs());
+                bounds.min.y = -y_abs;
+                bounds.max.y = y_abs;
+            }
+            bounds
         };
 
-        let margin_in_values = margin_points * bounds.size() / rect.size();
-        let bounds = bounds.expand2(margin_in_values);
+        let auto_bounds = apply_symmetrical(bounds);
+        let margin_in_values = margin_points * auto_bounds.size() / rect.size();
+        let auto_bounds = auto_bounds.expand2(margin_in_values);
+
+        let mut bounds = ui
+            .memory()
+            .data
+            .get_persisted::<Rect>(id)
+            .filter(|bounds| bounds.is_finite())
+            .unwrap_or(auto_bounds);
+
+        if response.double_clicked() {
+            bounds = auto_bounds;
+        } else {
+            if allow_drag && response.dragged() {
+                let transform = ScreenTransform::new(rect, bounds, log_x, log_y);
+                bounds = transform.translated_bounds(response.drag_delta());
+            }
+
+            if allow_zoom && response.hovered() {
+                let scroll_delta = ui.input().scroll_delta.y;
+                if scroll_delta != 0.0 {
+                    if let Some(pointer) = ui.input().pointer.hover_pos() {
+                        let transform = ScreenTransform::new(rect, bounds, log_x, log_y);
+                        let zoom_factor = (-scroll_delta * 0.001).exp();
+                        bounds = transform.zoomed_bounds(zoom_factor, pointer);
+                    }
+                }
+            }
+
+            bounds = apply_symmetrical(bounds);
+        }
+
+        ui.memory().data.insert_persisted(id, bounds);
 
         // Background:
         ui.painter().add(Shape::Rect {
@@ -290,12 +1512,13 @@ impl Widget for Plot {
 
         if bounds.is_finite() {
             let prepared = Prepared {
-                curves,
-                hlines,
-                to_screen: emath::RectTransform::from_to(bounds, rect),
-                from_screen: emath::RectTransform::from_to(rect, bounds),
+                items,
+                transform: ScreenTransform::new(rect, bounds, log_x, log_y),
                 show_x,
                 show_y,
+                show_axes,
+                legend,
+                id,
             };
             prepared.ui(ui, &response);
         }
@@ -304,108 +1527,495 @@ impl Widget for Plot {
     }
 }
 
-struct Prepared {
-    curves: Vec<Curve>,
-    hlines: Vec<HLine>,
+/// Maps between value-space and screen-space.
+///
+/// Normally this is just a linear [`emath::RectTransform`], but on a log-scaled axis
+/// a `log10` is applied to the value before the linear remap (and `10^v` on the way
+/// back out), so that `position_from_value`/`value_from_position` stay correct.
+struct ScreenTransform {
     to_screen: emath::RectTransform,
     from_screen: emath::RectTransform,
+    log_x: bool,
+    log_y: bool,
+}
+
+impl ScreenTransform {
+    /// Lower bound for a log-scaled axis whose auto-fit bounds touch or cross zero
+    /// (e.g. [`Curve::from_ys_f32`], whose x starts at index `0`, or any curve that
+    /// legitimately crosses zero). Clamping to `f32::MIN_POSITIVE` would make
+    /// [`generate_log_ticks`] iterate from decade ~-38 up to the real data's top
+    /// decade, so instead fall back to a handful of decades below the upper bound.
+    const NON_POSITIVE_LOG_DECADES: f32 = 6.0;
+
+    fn log_axis_min(min: f32, max: f32) -> f32 {
+        if min > 0.0 {
+            min
+        } else {
+            max.max(f32::MIN_POSITIVE) * 10f32.powf(-Self::NON_POSITIVE_LOG_DECADES)
+        }
+    }
+
+    /// Apply `log10` to whichever axes are log-scaled.
+    fn to_transformed(bounds: Rect, log_x: bool, log_y: bool) -> Rect {
+        Rect::from_min_max(
+            pos2(
+                if log_x {
+                    Self::log_axis_min(bounds.min.x, bounds.max.x).log10()
+                } else {
+                    bounds.min.x
+                },
+                if log_y {
+                    Self::log_axis_min(bounds.min.y, bounds.max.y).log10()
+                } else {
+                    bounds.min.y
+                },
+            ),
+            pos2(
+                if log_x {
+                    bounds.max.x.max(f32::MIN_POSITIVE).log10()
+                } else {
+                    bounds.max.x
+                },
+                if log_y {
+                    bounds.max.y.max(f32::MIN_POSITIVE).log10()
+                } else {
+                    bounds.max.y
+                },
+            ),
+        )
+    }
+
+    /// Inverse of [`Self::to_transformed`]: apply `10^v` to whichever axes are log-scaled.
+    fn to_original(bounds: Rect, log_x: bool, log_y: bool) -> Rect {
+        Rect::from_min_max(
+            pos2(
+                if log_x {
+                    10f32.powf(bounds.min.x)
+                } else {
+                    bounds.min.x
+                },
+                if log_y {
+                    10f32.powf(bounds.min.y)
+                } else {
+                    bounds.min.y
+                },
+            ),
+            pos2(
+                if log_x {
+                    10f32.powf(bounds.max.x)
+                } else {
+                    bounds.max.x
+                },
+                if log_y {
+                    10f32.powf(bounds.max.y)
+                } else {
+                    bounds.max.y
+                },
+            ),
+        )
+    }
+
+    fn new(screen: Rect, bounds: Rect, log_x: bool, log_y: bool) -> Self {
+        let transformed_bounds = Self::to_transformed(bounds, log_x, log_y);
+        Self {
+            to_screen: emath::RectTransform::from_to(transformed_bounds, screen),
+            from_screen: emath::RectTransform::from_to(screen, transformed_bounds),
+            log_x,
+            log_y,
+        }
+    }
+
+    fn position_from_value(&self, value: &Value) -> Pos2 {
+        let x = if self.log_x {
+            value.x.max(f64::MIN_POSITIVE).log10() as f32
+        } else {
+            value.x as f32
+        };
+        let y = if self.log_y {
+            value.y.max(f64::MIN_POSITIVE).log10() as f32
+        } else {
+            value.y as f32
+        };
+        self.to_screen * pos2(x, y)
+    }
+
+    fn value_from_position(&self, pos: Pos2) -> Value {
+        let v = self.from_screen * pos;
+        let x = if self.log_x {
+            10f64.powf(v.x as f64)
+        } else {
+            v.x as f64
+        };
+        let y = if self.log_y {
+            10f64.powf(v.y as f64)
+        } else {
+            v.y as f64
+        };
+        Value::new(x, y)
+    }
+
+    /// Range of the values, in original (un-logged) value space.
+    fn bounds(&self) -> Rect {
+        Self::to_original(*self.to_screen.from(), self.log_x, self.log_y)
+    }
+
+    /// Where on screen we paint
+    fn screen_rect(&self) -> Rect {
+        *self.to_screen.to()
+    }
+
+    /// New value-space bounds after panning the view by a screen-space `delta`, e.g. a
+    /// drag delta (content should follow the pointer, so the view moves opposite `delta`).
+    fn translated_bounds(&self, delta: Vec2) -> Rect {
+        let delta_transformed =
+            (self.from_screen * pos2(delta.x, delta.y)) - (self.from_screen * Pos2::ZERO);
+        let shifted = self.to_screen.from().translate(-delta_transformed);
+        Self::to_original(shifted, self.log_x, self.log_y)
+    }
+
+    /// New value-space bounds after zooming by `zoom_factor` around `center`, a point in
+    /// screen space (e.g. the pointer position). `zoom_factor < 1` zooms in.
+    fn zoomed_bounds(&self, zoom_factor: f32, center: Pos2) -> Rect {
+        let center = self.from_screen * center;
+        let bounds = *self.to_screen.from();
+        let scaled = Rect::from_min_max(
+            center + (bounds.min - center) * zoom_factor,
+            center + (bounds.max - center) * zoom_factor,
+        );
+        Self::to_original(scaled, self.log_x, self.log_y)
+    }
+}
+
+struct Prepared {
+    items: Vec<PlotItemKind>,
+    transform: ScreenTransform,
     show_x: bool,
     show_y: bool,
+    show_axes: bool,
+    legend: Option<Legend>,
+    id: Id,
 }
 
 impl Prepared {
     fn position_from_value(&self, value: &Value) -> Pos2 {
-        self.to_screen * pos2(value.x as f32, value.y as f32)
+        self.transform.position_from_value(value)
     }
 
     fn value_from_position(&self, pos: Pos2) -> Value {
-        let v = self.from_screen * pos;
-        Value::new(v.x, v.y)
+        self.transform.value_from_position(pos)
     }
 
-    /// Range of the values
-    fn bounds(&self) -> &Rect {
-        self.to_screen.from()
+    /// Range of the values, in original (un-logged) value space.
+    fn bounds(&self) -> Rect {
+        self.transform.bounds()
     }
 
     /// Where on screen we paint
-    fn screen_rect(&self) -> &Rect {
-        self.to_screen.to()
+    fn screen_rect(&self) -> Rect {
+        self.transform.screen_rect()
     }
 
     fn ui(&self, ui: &mut Ui, response: &Response) {
-        let mut shapes = Vec::with_capacity(self.hlines.len() + self.curves.len() + 2);
-
-        for &hline in &self.hlines {
-            let HLine { y, stroke } = hline;
-            let points = [
-                self.position_from_value(&Value::new(self.bounds().left(), y)),
-                self.position_from_value(&Value::new(self.bounds().right(), y)),
-            ];
-            shapes.push(Shape::line_segment(points, stroke));
-        }
-
-        for curve in &self.curves {
-            let stroke = curve.stroke;
-            let values = &curve.values;
-            if values.len() == 1 {
-                let point = self.position_from_value(&values[0]);
-                shapes.push(Shape::circle_filled(
-                    point,
-                    stroke.width / 2.0,
-                    stroke.color,
-                ));
-            } else if values.len() > 1 {
-                shapes.push(Shape::line(
-                    values.iter().map(|v| self.position_from_value(v)).collect(),
-                    stroke,
-                ));
+        let mut shapes = Vec::with_capacity(self.items.len() + 2);
+
+        if self.show_axes {
+            self.paint_axes(ui, &mut shapes);
+        }
+
+        let hidden = self
+            .legend
+            .as_ref()
+            .map(|legend| self.update_legend_visibility(ui, legend, response))
+            .unwrap_or_default();
+
+        for item in &self.items {
+            if hidden.contains(item.name()) {
+                continue;
             }
+            item.shapes(ui, &self.transform, &mut shapes);
+        }
+
+        if let Some(legend) = &self.legend {
+            self.paint_legend(ui, legend, &hidden, &mut shapes);
         }
 
         if response.hovered() {
             if let Some(pointer) = ui.input().pointer.tooltip_pos() {
-                self.hover(ui, pointer, &mut shapes);
+                self.hover(ui, pointer, &hidden, &mut shapes);
+            }
+        }
+
+        ui.painter().sub_region(self.screen_rect()).extend(shapes);
+    }
+
+    /// Screen-space layout of the legend box: its background rect, and per-entry
+    /// `(swatch_and_label_rect, name, color)` rows, in the same order as `self.items`.
+    fn legend_layout(&self, ui: &Ui, legend: &Legend) -> (Rect, Vec<(Rect, &str, Color32)>) {
+        let rect = self.screen_rect();
+        let padding = 6.0;
+        let row_height = 16.0;
+        let swatch_width = 20.0;
+        let margin = 8.0;
+
+        let entries: Vec<(&str, Color32)> = self
+            .items
+            .iter()
+            .map(|item| (item.name(), item.color()))
+            .filter(|(name, _)| !name.is_empty())
+            .collect();
+
+        let text_width = entries
+            .iter()
+            .map(|(name, _)| {
+                ui.fonts()
+                    .layout_single_line(TextStyle::Small, (*name).to_owned())
+                    .size
+                    .x
+            })
+            .fold(0.0_f32, f32::max);
+
+        let box_size = vec2(
+            padding * 2.0 + swatch_width + text_width,
+            padding * 2.0 + row_height * entries.len() as f32,
+        );
+
+        let box_min = match legend.corner {
+            Corner::LeftTop => pos2(rect.left() + margin, rect.top() + margin),
+            Corner::RightTop => pos2(rect.right() - margin - box_size.x, rect.top() + margin),
+            Corner::LeftBottom => pos2(rect.left() + margin, rect.bottom() - margin - box_size.y),
+            Corner::RightBottom => pos2(
+                rect.right() - margin - box_size.x,
+                rect.bottom() - margin - box_size.y,
+            ),
+        };
+        let box_rect = Rect::from_min_size(box_min, box_size);
+
+        let rows = entries
+            .into_iter()
+            .enumerate()
+            .map(|(i, (name, color))| {
+                let row_rect = Rect::from_min_size(
+                    pos2(
+                        box_rect.left(),
+                        box_rect.top() + padding + row_height * i as f32,
+                    ),
+                    vec2(box_size.x, row_height),
+                );
+                (row_rect, name, color)
+            })
+            .collect();
+
+        (box_rect, rows)
+    }
+
+    /// If the legend was clicked, toggle that entry's visibility in memory. Returns
+    /// the (possibly just-updated) set of hidden item names.
+    fn update_legend_visibility(
+        &self,
+        ui: &mut Ui,
+        legend: &Legend,
+        response: &Response,
+    ) -> HashSet<String> {
+        let mut hidden = ui
+            .memory()
+            .data
+            .get_persisted_mut_or_default::<HashSet<String>>(self.id)
+            .clone();
+
+        if response.clicked() {
+            if let Some(pointer) = ui.input().pointer.interact_pos() {
+                let (_, rows) = self.legend_layout(ui, legend);
+                if let Some((_, name, _)) =
+                    rows.into_iter().find(|(rect, _, _)| rect.contains(pointer))
+                {
+                    if !hidden.remove(name) {
+                        hidden.insert(name.to_owned());
+                    }
+                    ui.memory().data.insert_persisted(self.id, hidden.clone());
+                }
             }
         }
 
-        ui.painter().sub_region(*self.screen_rect()).extend(shapes);
+        hidden
+    }
+
+    fn paint_legend(
+        &self,
+        ui: &Ui,
+        legend: &Legend,
+        hidden: &HashSet<String>,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let (box_rect, rows) = self.legend_layout(ui, legend);
+        if rows.is_empty() {
+            return;
+        }
+
+        shapes.push(Shape::Rect {
+            rect: box_rect,
+            corner_radius: 4.0,
+            fill: Color32::from_black_alpha(200),
+            stroke: Stroke::new(1.0, Color32::from_gray(80)),
+        });
+
+        for (row_rect, name, color) in rows {
+            let is_hidden = hidden.contains(name);
+            let swatch_color = if is_hidden {
+                color.linear_multiply(0.2)
+            } else {
+                color
+            };
+            let text_color = if is_hidden {
+                Color32::from_gray(100)
+            } else {
+                Color32::from_gray(230)
+            };
+
+            let swatch_y = row_rect.center().y;
+            shapes.push(Shape::line_segment(
+                [
+                    pos2(row_rect.left() + 4.0, swatch_y),
+                    pos2(row_rect.left() + 16.0, swatch_y),
+                ],
+                Stroke::new(2.0, swatch_color),
+            ));
+            shapes.push(Shape::text(
+                ui.fonts(),
+                pos2(row_rect.left() + 22.0, swatch_y),
+                Align2::LEFT_CENTER,
+                name.to_owned(),
+                TextStyle::Small,
+                text_color,
+            ));
+        }
+    }
+
+    /// Faint gridlines and edge labels at "nice" tick positions on both axes.
+    fn paint_axes(&self, ui: &Ui, shapes: &mut Vec<Shape>) {
+        let rect = self.screen_rect();
+        let bounds = self.bounds();
+        if !bounds.is_finite() || bounds.width() <= 0.0 || bounds.height() <= 0.0 {
+            return;
+        }
+
+        let major_stroke = Stroke::new(1.0, Color32::from_gray(36));
+        let minor_stroke = Stroke::new(1.0, Color32::from_gray(22));
+        let label_color = Color32::from_gray(150);
+        let min_label_spacing = 60.0;
+
+        let d_dpixel = bounds.size() / rect.size();
+        let x_decimals = decimals_for_resolution(d_dpixel.x);
+        let y_decimals = decimals_for_resolution(d_dpixel.y);
+
+        let (x_major, x_minor) = if self.transform.log_x {
+            generate_log_ticks(bounds.min.x as f64, bounds.max.x as f64)
+        } else {
+            let step = nice_tick_step(bounds.width() as f64, rect.width(), min_label_spacing);
+            (
+                generate_ticks(bounds.min.x as f64, bounds.max.x as f64, step),
+                Vec::new(),
+            )
+        };
+        for x in &x_minor {
+            let screen_x = self
+                .position_from_value(&Value::new(*x, bounds.min.y as f64))
+                .x;
+            shapes.push(Shape::line_segment(
+                [pos2(screen_x, rect.top()), pos2(screen_x, rect.bottom())],
+                minor_stroke,
+            ));
+        }
+        for x in &x_major {
+            let screen_x = self
+                .position_from_value(&Value::new(*x, bounds.min.y as f64))
+                .x;
+            shapes.push(Shape::line_segment(
+                [pos2(screen_x, rect.top()), pos2(screen_x, rect.bottom())],
+                major_stroke,
+            ));
+            let decimals = if self.transform.log_x {
+                decimals_for_log_tick(*x)
+            } else {
+                x_decimals
+            };
+            shapes.push(Shape::text(
+                ui.fonts(),
+                pos2(screen_x, rect.bottom()),
+                Align2::CENTER_BOTTOM,
+                format!("{:.*}", decimals, x),
+                TextStyle::Small,
+                label_color,
+            ));
+        }
+
+        let (y_major, y_minor) = if self.transform.log_y {
+            generate_log_ticks(bounds.min.y as f64, bounds.max.y as f64)
+        } else {
+            let step = nice_tick_step(bounds.height() as f64, rect.height(), min_label_spacing);
+            (
+                generate_ticks(bounds.min.y as f64, bounds.max.y as f64, step),
+                Vec::new(),
+            )
+        };
+        for y in &y_minor {
+            let screen_y = self
+                .position_from_value(&Value::new(bounds.min.x as f64, *y))
+                .y;
+            shapes.push(Shape::line_segment(
+                [pos2(rect.left(), screen_y), pos2(rect.right(), screen_y)],
+                minor_stroke,
+            ));
+        }
+        for y in &y_major {
+            let screen_y = self
+                .position_from_value(&Value::new(bounds.min.x as f64, *y))
+                .y;
+            shapes.push(Shape::line_segment(
+                [pos2(rect.left(), screen_y), pos2(rect.right(), screen_y)],
+                major_stroke,
+            ));
+            let decimals = if self.transform.log_y {
+                decimals_for_log_tick(*y)
+            } else {
+                y_decimals
+            };
+            shapes.push(Shape::text(
+                ui.fonts(),
+                pos2(rect.left(), screen_y),
+                Align2::LEFT_BOTTOM,
+                format!("{:.*}", decimals, y),
+                TextStyle::Small,
+                label_color,
+            ));
+        }
     }
 
-    fn hover(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) {
+    fn hover(&self, ui: &Ui, pointer: Pos2, hidden: &HashSet<String>, shapes: &mut Vec<Shape>) {
         if !self.show_x && !self.show_y {
             return;
         }
 
         let interact_radius: f32 = 16.0;
-        let mut closest_value = None;
-        let mut closest_curve = None;
-        let mut closest_dist_sq = interact_radius.powi(2);
-        for curve in &self.curves {
-            for value in &curve.values {
-                let pos = self.position_from_value(value);
-                let dist_sq = pointer.distance_sq(pos);
-                if dist_sq < closest_dist_sq {
-                    closest_dist_sq = dist_sq;
-                    closest_value = Some(value);
-                    closest_curve = Some(curve);
-                }
+        let mut closest: Option<HoverHit> = None;
+        for item in &self.items {
+            if hidden.contains(item.name()) {
+                continue;
             }
-        }
-
-        let mut prefix = String::new();
-        if let Some(curve) = closest_curve {
-            if !curve.name.is_empty() {
-                prefix = format!("{}\n", curve.name);
+            if let Some(hit) = item.find_closest(&self.transform, pointer) {
+                if hit.dist_sq < interact_radius.powi(2)
+                    && closest.as_ref().map_or(true, |c| hit.dist_sq < c.dist_sq)
+                {
+                    closest = Some(hit);
+                }
             }
         }
 
-        let value = if let Some(value) = closest_value {
-            let position = self.position_from_value(value);
+        let (value, prefix, detail) = if let Some(hit) = closest {
+            let position = self.position_from_value(&hit.value);
             shapes.push(Shape::circle_filled(position, 3.0, Color32::WHITE));
-            *value
+            (hit.value, hit.prefix, hit.detail)
         } else {
-            self.value_from_position(pointer)
+            (self.value_from_position(pointer), String::new(), None)
         };
         let pointer = self.position_from_value(&value);
 
@@ -426,10 +2036,20 @@ impl Prepared {
             ));
         }
 
-        let text = {
+        let text = if let Some(detail) = detail {
+            format!("{}{}", prefix, detail)
+        } else {
             let d_dpixel = self.bounds().size() / self.screen_rect().size();
-            let x_decimals = ((-d_dpixel.x.log10()).ceil().at_least(0.0) as usize).at_most(6);
-            let y_decimals = ((-d_dpixel.y.log10()).ceil().at_least(0.0) as usize).at_most(6);
+            let x_decimals = if self.transform.log_x {
+                decimals_for_log_tick(value.x)
+            } else {
+                decimals_for_resolution(d_dpixel.x)
+            };
+            let y_decimals = if self.transform.log_y {
+                decimals_for_log_tick(value.y)
+            } else {
+                decimals_for_resolution(d_dpixel.y)
+            };
             if self.show_x && self.show_y {
                 format!(
                     "{}x = {:.*}\ny = {:.*}",
@@ -27,7 +27,7 @@ impl super::Demo for PlotDemo {
 
 impl super::View for PlotDemo {
     fn ui(&mut self, ui: &mut Ui) {
-        use egui::plot::{Curve, Plot, Value};
+        use egui::plot::{Curve, Legend, Plot, Value};
         use std::f64::consts::TAU;
 
         ui.checkbox(&mut self.animate, "animate");
@@ -57,6 +57,19 @@ impl super::View for PlotDemo {
             .color(Color32::from_rgb(100, 150, 250))
             .name("x=sin(4t), y=sin(6t)");
 
-        ui.add(Plot::default().curve(circle).curve(curve).aspect_ratio(1.0));
+        // Sampled adaptively at draw time instead of baking in a fixed point count,
+        // so zooming in keeps revealing detail.
+        let sin = Curve::from_explicit_fn(move |x| (x + time).sin(), -TAU..=TAU)
+            .color(Color32::from_rgb(250, 150, 100))
+            .name("sin(x)");
+
+        ui.add(
+            Plot::default()
+                .curve(circle)
+                .curve(curve)
+                .curve(sin)
+                .legend(Legend::default())
+                .aspect_ratio(1.0),
+        );
     }
 }